@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod attestation_timestamp_tests {
+    use crate::test_support::setup_with_anchor;
+    use crate::Error;
+    use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+
+    #[test]
+    fn test_near_now_timestamp_accepted() {
+        let env = Env::default();
+        let (client, issuer) = setup_with_anchor(&env);
+        let subject = Address::generate(&env);
+
+        client.submit_attestation_tracked(
+            &issuer,
+            &subject,
+            &1_000_060,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &Bytes::new(&env),
+        );
+    }
+
+    #[test]
+    fn test_far_future_timestamp_rejected() {
+        let env = Env::default();
+        let (client, issuer) = setup_with_anchor(&env);
+        let subject = Address::generate(&env);
+
+        let result = client.try_submit_attestation_tracked(
+            &issuer,
+            &subject,
+            &(1_000_000 + 86_400),
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &Bytes::new(&env),
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+    }
+
+    #[test]
+    fn test_zero_timestamp_rejected() {
+        let env = Env::default();
+        let (client, issuer) = setup_with_anchor(&env);
+        let subject = Address::generate(&env);
+
+        let result = client.try_submit_attestation_tracked(
+            &issuer,
+            &subject,
+            &0,
+            &BytesN::from_array(&env, &[3u8; 32]),
+            &Bytes::new(&env),
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+    }
+
+    #[test]
+    fn test_session_attestation_far_future_rejected() {
+        let env = Env::default();
+        let (client, issuer) = setup_with_anchor(&env);
+        let subject = Address::generate(&env);
+        let session_id = client.create_session(&issuer);
+
+        let result = client.try_submit_attestation_with_session(
+            &session_id,
+            &issuer,
+            &subject,
+            &(1_000_000 + 86_400),
+            &BytesN::from_array(&env, &[4u8; 32]),
+            &Bytes::new(&env),
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidTimestamp)));
+    }
+}
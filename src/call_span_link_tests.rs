@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod call_span_link_tests {
+    use crate::test_support::{register_anchor, setup};
+    use crate::ServiceType;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+
+    #[test]
+    fn test_tracked_quote_resolves_to_span() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let anchor = register_anchor(&env, &client);
+        client.configure_services(&anchor, &vec![&env, ServiceType::Quotes]);
+
+        client.submit_quote_tracked(
+            &anchor,
+            &String::from_str(&env, "USD"),
+            &String::from_str(&env, "USDC"),
+            &10000,
+            &50,
+            &100,
+            &100000,
+            &1_003_600,
+        );
+
+        let history = client.get_request_history(&10);
+        let record = history.recent_calls.get(0).unwrap();
+
+        let span = client.get_span_for_call(&record.call_id).unwrap();
+        assert_eq!(span.request_id.id, record.request_id);
+        assert_eq!(span.operation, String::from_str(&env, "submit_quote"));
+        assert_eq!(span.actor, anchor);
+        assert_eq!(span.status, String::from_str(&env, "success"));
+    }
+
+    #[test]
+    fn test_tracked_attestation_resolves_to_span() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let issuer = register_anchor(&env, &client);
+        let subject = Address::generate(&env);
+
+        client.submit_attestation_tracked(
+            &issuer,
+            &subject,
+            &1_000_000,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &Bytes::new(&env),
+        );
+
+        let history = client.get_request_history(&10);
+        let record = history.recent_calls.get(0).unwrap();
+
+        let span = client.get_span_for_call(&record.call_id).unwrap();
+        assert_eq!(span.request_id.id, record.request_id);
+        assert_eq!(span.operation, String::from_str(&env, "submit_attestation"));
+        assert_eq!(span.actor, issuer);
+    }
+
+    #[test]
+    fn test_unknown_call_has_no_span() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        assert!(client.get_span_for_call(&999).is_none());
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod capabilities_size_tests {
+    use crate::test_support::setup_with_anchor;
+    use crate::Error;
+    use soroban_sdk::{Env, String};
+
+    fn capabilities_of_len(env: &Env, len: usize) -> String {
+        let mut doc = alloc::string::String::new();
+        for _ in 0..len {
+            doc.push('x');
+        }
+        String::from_str(env, &doc)
+    }
+
+    #[test]
+    fn test_cache_capabilities_under_limit() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let toml_url = String::from_str(&env, "https://example.com/.well-known/stellar.toml");
+        let capabilities = String::from_str(&env, "{\"deposit\":true,\"withdraw\":true}");
+        client.cache_capabilities(&anchor, &toml_url, &capabilities, &3600);
+
+        let cached = client.get_cached_capabilities(&anchor);
+        assert_eq!(cached.capabilities, capabilities);
+    }
+
+    #[test]
+    fn test_cache_capabilities_at_limit() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let toml_url = String::from_str(&env, "https://example.com/.well-known/stellar.toml");
+        let capabilities = capabilities_of_len(&env, 8192);
+        client.cache_capabilities(&anchor, &toml_url, &capabilities, &3600);
+
+        let cached = client.get_cached_capabilities(&anchor);
+        assert_eq!(cached.capabilities.len(), 8192);
+        assert_eq!(cached.capabilities, capabilities);
+    }
+
+    #[test]
+    fn test_cache_capabilities_over_limit_rejected() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let toml_url = String::from_str(&env, "https://example.com/.well-known/stellar.toml");
+        let capabilities = capabilities_of_len(&env, 8193);
+
+        let result = client.try_cache_capabilities(&anchor, &toml_url, &capabilities, &3600);
+        assert_eq!(result, Err(Ok(Error::WebhookPayloadTooLarge)));
+        assert!(client.try_get_cached_capabilities(&anchor).is_err());
+    }
+}
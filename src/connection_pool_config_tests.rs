@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod connection_pool_config_tests {
+    use crate::test_support::setup;
+    use crate::Error;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_valid_pool_config() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        client.configure_connection_pool(&10, &60, &30, &true);
+
+        let config = client.get_pool_config();
+        assert_eq!(config.max_connections, 10);
+        assert_eq!(config.idle_timeout_seconds, 60);
+        assert_eq!(config.connection_timeout_seconds, 30);
+        assert!(config.reuse_connections);
+    }
+
+    #[test]
+    fn test_reject_zero_max_connections() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let result = client.try_configure_connection_pool(&0, &60, &30, &true);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_reject_zero_connection_timeout() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let result = client.try_configure_connection_pool(&10, &60, &0, &true);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_reject_reuse_without_idle_timeout() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let result = client.try_configure_connection_pool(&10, &0, &30, &true);
+        assert_eq!(result, Err(Ok(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_zero_idle_timeout_allowed_without_reuse() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        client.configure_connection_pool(&10, &0, &30, &false);
+        assert!(!client.get_pool_config().reuse_connections);
+    }
+}
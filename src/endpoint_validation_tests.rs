@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod endpoint_validation_tests {
+    use crate::{AnchorKitContract, Error};
+    use soroban_sdk::{Env, String};
+
+    fn validate(env: &Env, url: &str) -> Result<(), Error> {
+        AnchorKitContract::validate_endpoint_url(&String::from_str(env, url))
+    }
+
+    #[test]
+    fn test_valid_https_endpoint() {
+        let env = Env::default();
+        assert_eq!(validate(&env, "https://anchor.example.com"), Ok(()));
+    }
+
+    #[test]
+    fn test_reject_too_short_endpoint() {
+        let env = Env::default();
+        assert_eq!(validate(&env, ""), Err(Error::InvalidEndpointFormat));
+        assert_eq!(
+            validate(&env, "https://"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_non_https_endpoint() {
+        let env = Env::default();
+        assert_eq!(
+            validate(&env, "http://anchor.example.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "ftp://anchor.example.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_endpoint_with_spaces() {
+        let env = Env::default();
+        assert_eq!(
+            validate(&env, "https://anchor example.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_oversized_endpoint() {
+        let env = Env::default();
+        let mut url = alloc::string::String::from("https://");
+        url.push_str(&"a".repeat(300));
+        assert_eq!(validate(&env, &url), Err(Error::InvalidEndpointFormat));
+    }
+}
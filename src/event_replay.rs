@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use soroban_sdk::String;
+
+use crate::{Error, OperationLogged};
+
+/// An `OperationLogged` event payload as observed off-chain, paired with the
+/// close time of the ledger that emitted it.
+#[derive(Clone, Debug)]
+pub struct LoggedOperation {
+    pub ledger_timestamp: u64,
+    pub event: OperationLogged,
+}
+
+/// A session operation reconstructed from its `OperationLogged` event.
+/// Mirrors `OperationContext` minus `result_data`, which the event does not carry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplayedOperation {
+    pub log_id: u64,
+    pub session_id: u64,
+    pub operation_index: u64,
+    pub operation_type: String,
+    pub timestamp: u64,
+    pub status: String,
+}
+
+/// Reconstruct the ordered operation list of a session from `OperationLogged` events.
+///
+/// Host-side helper for integrators; it is not exposed as a contract method.
+/// Events for other sessions are ignored and the input may arrive in any order.
+/// Operation indices must run contiguously from 0, otherwise `ProtocolInvalidPayload`
+/// is returned.
+pub fn replay_session_operations(
+    session_id: u64,
+    events: &[LoggedOperation],
+) -> Result<Vec<ReplayedOperation>, Error> {
+    let mut ordered: Vec<&LoggedOperation> = events
+        .iter()
+        .filter(|logged| logged.event.session_id == session_id)
+        .collect();
+    ordered.sort_by_key(|logged| logged.event.operation_index);
+
+    let mut operations = Vec::with_capacity(ordered.len());
+    for (expected_index, logged) in ordered.iter().enumerate() {
+        // A gap or duplicate means the stream is missing or repeating events
+        if logged.event.operation_index != expected_index as u64 {
+            return Err(Error::ProtocolInvalidPayload);
+        }
+
+        operations.push(ReplayedOperation {
+            log_id: logged.event.log_id,
+            session_id,
+            operation_index: logged.event.operation_index,
+            operation_type: logged.event.operation_type.clone(),
+            timestamp: logged.ledger_timestamp,
+            status: logged.event.status.clone(),
+        });
+    }
+
+    Ok(operations)
+}
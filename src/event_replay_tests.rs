@@ -0,0 +1,85 @@
+#[cfg(all(test, feature = "std"))]
+mod event_replay_tests {
+    use crate::event_replay::{replay_session_operations, LoggedOperation};
+    use crate::{Error, OperationLogged};
+    use soroban_sdk::{Env, String};
+
+    fn logged(
+        env: &Env,
+        session_id: u64,
+        operation_index: u64,
+        operation_type: &str,
+        ledger_timestamp: u64,
+    ) -> LoggedOperation {
+        LoggedOperation {
+            ledger_timestamp,
+            event: OperationLogged {
+                log_id: operation_index,
+                session_id,
+                operation_index,
+                operation_type: String::from_str(env, operation_type),
+                status: String::from_str(env, "success"),
+            },
+        }
+    }
+
+    #[test]
+    fn test_replay_in_order_stream() {
+        let env = Env::default();
+        let events = [
+            logged(&env, 7, 0, "register", 100),
+            logged(&env, 7, 1, "attest", 110),
+            logged(&env, 7, 2, "quote", 120),
+        ];
+
+        let operations = replay_session_operations(7, &events).unwrap();
+
+        assert_eq!(operations.len(), 3);
+        let last = &operations[2];
+        assert_eq!(last.session_id, 7);
+        assert_eq!(last.operation_index, 2);
+        assert_eq!(last.operation_type, String::from_str(&env, "quote"));
+        assert_eq!(last.timestamp, 120);
+        assert_eq!(last.log_id, 2);
+    }
+
+    #[test]
+    fn test_replay_orders_and_filters_sessions() {
+        let env = Env::default();
+        let events = [
+            logged(&env, 7, 1, "attest", 110),
+            logged(&env, 8, 0, "register", 105),
+            logged(&env, 7, 0, "register", 100),
+        ];
+
+        let operations = replay_session_operations(7, &events).unwrap();
+
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].operation_index, 0);
+        assert_eq!(operations[1].operation_index, 1);
+    }
+
+    #[test]
+    fn test_replay_detects_gap() {
+        let env = Env::default();
+        let events = [
+            logged(&env, 7, 0, "register", 100),
+            logged(&env, 7, 2, "quote", 120),
+        ];
+
+        let result = replay_session_operations(7, &events);
+        assert_eq!(result, Err(Error::ProtocolInvalidPayload));
+    }
+
+    #[test]
+    fn test_replay_detects_duplicate() {
+        let env = Env::default();
+        let events = [
+            logged(&env, 7, 0, "register", 100),
+            logged(&env, 7, 0, "register", 100),
+        ];
+
+        let result = replay_session_operations(7, &events);
+        assert_eq!(result, Err(Error::ProtocolInvalidPayload));
+    }
+}
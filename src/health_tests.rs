@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod health_tests {
+    use crate::test_support::{register_anchor, setup, setup_with_anchor};
+    use crate::{Error, ValidationStep};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    // ============ Batch health ============
+
+    #[test]
+    fn test_batch_health_mixed_results() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let healthy = register_anchor(&env, &client);
+        let unknown = Address::generate(&env);
+        let degraded = register_anchor(&env, &client);
+
+        client.update_health_status(&healthy, &120, &0, &9990);
+        client.update_health_status(&degraded, &900, &7, &8500);
+
+        let statuses =
+            client.get_health_statuses(&vec![&env, healthy.clone(), unknown, degraded.clone()]);
+        assert_eq!(statuses.len(), 3);
+
+        let first = statuses.get(0).unwrap().unwrap();
+        assert_eq!(first.anchor, healthy);
+        assert_eq!(first.latency_ms, 120);
+
+        assert!(statuses.get(1).unwrap().is_none());
+
+        let third = statuses.get(2).unwrap().unwrap();
+        assert_eq!(third.anchor, degraded);
+        assert_eq!(third.failure_count, 7);
+    }
+
+    #[test]
+    fn test_batch_health_empty_input() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let statuses = client.get_health_statuses(&Vec::new(&env));
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn test_batch_health_rejects_oversized_input() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let mut anchors = Vec::new(&env);
+        for _ in 0..51 {
+            anchors.push_back(Address::generate(&env));
+        }
+
+        let result = client.try_get_health_statuses(&anchors);
+        assert_eq!(result, Err(Ok(Error::WebhookPayloadTooLarge)));
+    }
+
+    // ============ Endpoint health ============
+
+    // The populated (endpoint, health) tuple and the fully validated skeleton are not
+    // covered: no entrypoint in this tree stores an endpoint, and the Storage helper
+    // that does lives in src/storage.rs, which is not part of this snapshot.
+
+    fn find_step(steps: &Vec<ValidationStep>, name: &String) -> Option<ValidationStep> {
+        steps.iter().find(|step| step.step_name == *name)
+    }
+
+    #[test]
+    fn test_endpoint_with_health_requires_endpoint() {
+        let env = Env::default();
+        let (client, attestor) = setup_with_anchor(&env);
+
+        // Health alone is not enough without an endpoint on record
+        client.update_health_status(&attestor, &120, &0, &9990);
+
+        let result = client.try_get_endpoint_with_health(&attestor);
+        assert_eq!(result, Err(Ok(Error::EndpointNotFound)));
+    }
+
+    #[test]
+    fn test_auth_skeleton_missing_health_is_not_pending() {
+        let env = Env::default();
+        let (client, attestor) = setup_with_anchor(&env);
+
+        let skeleton = client.get_auth_validation_skeleton(&attestor);
+        let step = find_step(
+            &skeleton.validation_steps,
+            &String::from_str(&env, "No endpoint health reported"),
+        )
+        .unwrap();
+        assert!(!step.is_complete);
+        assert!(!step.is_loading);
+    }
+
+    #[test]
+    fn test_auth_skeleton_reports_health() {
+        let env = Env::default();
+        let (client, attestor) = setup_with_anchor(&env);
+
+        client.update_health_status(&attestor, &120, &0, &9990);
+
+        let skeleton = client.get_auth_validation_skeleton(&attestor);
+        let step = find_step(
+            &skeleton.validation_steps,
+            &String::from_str(&env, "Endpoint health reported"),
+        )
+        .unwrap();
+        assert!(step.is_complete);
+        assert!(find_step(
+            &skeleton.validation_steps,
+            &String::from_str(&env, "No endpoint health reported"),
+        )
+        .is_none());
+    }
+}
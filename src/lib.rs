@@ -19,6 +19,8 @@ mod connection_pool;
 mod credentials;
 mod error_mapping;
 mod errors;
+#[cfg(feature = "std")]
+mod event_replay;
 mod events;
 mod logging;
 mod metadata_cache;
@@ -42,6 +44,9 @@ mod types;
 mod validation;
 mod webhook_middleware;
 
+#[cfg(test)]
+mod test_support;
+
 #[cfg(test)]
 mod deterministic_hash_tests;
 #[cfg(test)]
@@ -95,6 +100,42 @@ mod logging_tests;
 #[cfg(test)]
 mod transaction_state_tracker_tests;
 
+#[cfg(test)]
+mod sep24_fee_tests;
+
+#[cfg(test)]
+mod quote_tests;
+
+#[cfg(test)]
+mod endpoint_validation_tests;
+
+#[cfg(test)]
+mod service_tests;
+
+#[cfg(test)]
+mod health_tests;
+
+#[cfg(test)]
+mod sep10_domain_tests;
+
+#[cfg(test)]
+mod call_span_link_tests;
+
+#[cfg(test)]
+mod connection_pool_config_tests;
+
+#[cfg(test)]
+mod routing_operation_tests;
+
+#[cfg(test)]
+mod capabilities_size_tests;
+
+#[cfg(test)]
+mod attestation_timestamp_tests;
+
+#[cfg(all(test, feature = "std"))]
+mod event_replay_tests;
+
 
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
@@ -111,6 +152,8 @@ pub use events::{
     OperationLogged, QuoteReceived, QuoteSubmitted, ServicesConfigured, SessionCreated,
     SettlementConfirmed, TransferInitiated,
 };
+#[cfg(feature = "std")]
+pub use event_replay::{replay_session_operations, LoggedOperation, ReplayedOperation};
 pub use logging::{LogEntry, LogLevel, LoggingConfig, Logger, RequestLog};
 pub use metadata_cache::{CachedCapabilities, CachedMetadata, MetadataCache};
 pub use rate_limiter::{RateLimitConfig, RateLimiter};
@@ -154,6 +197,21 @@ pub use webhook_middleware::{
     WebhookSecurityConfig, WebhookValidationResult,
 };
 
+/// Maximum number of entries accepted by batch read methods.
+const MAX_BATCH_READ_SIZE: u32 = 50;
+
+/// Fee percentages are basis points; 10000 = 100%.
+const MAX_FEE_PERCENTAGE: u32 = 10000;
+
+/// Maximum length in bytes of a cached capabilities document.
+const MAX_CAPABILITIES_LENGTH: u32 = 8192;
+
+/// Quote rates are fixed point with 4 decimals; 10000 = 1.0.
+const RATE_PRECISION: u128 = 10000;
+
+/// How far ahead of ledger time, in seconds, an attestation timestamp may be.
+const MAX_ATTESTATION_FUTURE_SKEW: u64 = 300;
+
 #[contract]
 pub struct AnchorKitContract;
 
@@ -393,6 +451,16 @@ impl AnchorKitContract {
         Storage::get_endpoint(&env, &attestor)
     }
 
+    /// Get the endpoint configuration for an attestor together with its latest health status.
+    pub fn get_endpoint_with_health(
+        env: Env,
+        attestor: Address,
+    ) -> Result<(Endpoint, Option<HealthStatus>), Error> {
+        let endpoint = Storage::get_endpoint(&env, &attestor)?;
+        let health = Storage::get_health_status(&env, &attestor);
+        Ok((endpoint, health))
+    }
+
     /// Configure supported services for an anchor. Callable by the anchor.
     pub fn configure_services(
         env: Env,
@@ -408,6 +476,9 @@ impl AnchorKitContract {
             return Err(Error::AttestorNotRegistered);
         }
 
+        // Store in canonical order so equal service sets compare and hash equally
+        let services = Self::normalize_services(&services);
+
         let anchor_services = AnchorServices {
             anchor: anchor.clone(),
             services: services.clone(),
@@ -425,6 +496,32 @@ impl AnchorKitContract {
         Ok(anchor_services.services)
     }
 
+    /// Get services for an anchor, falling back to its cached stellar.toml.
+    /// Configured services take precedence; otherwise transfer servers imply
+    /// deposits/withdrawals and KYC or web auth endpoints imply KYC.
+    pub fn get_effective_services(env: Env, anchor: Address) -> Vec<ServiceType> {
+        if let Ok(anchor_services) = Storage::get_anchor_services(&env, &anchor) {
+            return anchor_services.services;
+        }
+
+        let mut services: Vec<ServiceType> = Vec::new(&env);
+        let toml = match anchor_info_discovery::AnchorInfoDiscovery::get_cached(&env, &anchor) {
+            Ok(toml) => toml,
+            Err(_) => return services,
+        };
+
+        if !toml.transfer_server.is_empty() || !toml.transfer_server_sep0024.is_empty() {
+            services.push_back(ServiceType::Deposits);
+            services.push_back(ServiceType::Withdrawals);
+        }
+
+        if !toml.kyc_server.is_empty() || !toml.web_auth_endpoint.is_empty() {
+            services.push_back(ServiceType::KYC);
+        }
+
+        Self::normalize_services(&services)
+    }
+
     /// Check if an anchor supports a specific service.
     pub fn supports_service(env: Env, anchor: Address, service: ServiceType) -> bool {
         if let Ok(anchor_services) = Storage::get_anchor_services(&env, &anchor) {
@@ -574,7 +671,7 @@ impl AnchorKitContract {
     ) -> Result<u64, Error> {
         issuer.require_auth();
 
-        if timestamp == 0 {
+        if Self::validate_attestation_timestamp(&env, timestamp).is_err() {
             Self::log_session_operation(&env, session_id, &issuer, "attest", "failed", 0)?;
             return Err(Error::InvalidTimestamp);
         }
@@ -662,6 +759,7 @@ impl AnchorKitContract {
     }
 
     /// Submit a quote from an anchor. Only callable by registered attestors.
+    /// `fee_percentage` is in basis points, so 10000 = 100%.
     pub fn submit_quote(
         env: Env,
         anchor: Address,
@@ -688,6 +786,14 @@ impl AnchorKitContract {
             return Err(Error::InvalidQuote);
         }
 
+        if minimum_amount > maximum_amount {
+            return Err(Error::InvalidQuote);
+        }
+
+        if fee_percentage > MAX_FEE_PERCENTAGE {
+            return Err(Error::InvalidQuote);
+        }
+
         if let Ok(services) = Storage::get_anchor_services(&env, &anchor) {
             if !services.services.contains(&ServiceType::Quotes) {
                 return Err(Error::InvalidServiceType);
@@ -730,6 +836,36 @@ impl AnchorKitContract {
         Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::InvalidQuote)
     }
 
+    /// Get several quotes at once, aligned to the input refs.
+    /// Missing quotes are returned as `None`.
+    pub fn get_quotes(
+        env: Env,
+        refs: Vec<(Address, u64)>,
+    ) -> Result<Vec<Option<QuoteData>>, Error> {
+        if refs.len() > MAX_BATCH_READ_SIZE {
+            return Err(Error::WebhookPayloadTooLarge);
+        }
+
+        let mut quotes: Vec<Option<QuoteData>> = Vec::new(&env);
+        for (anchor, quote_id) in refs.iter() {
+            quotes.push_back(Storage::get_quote(&env, &anchor, quote_id));
+        }
+
+        Ok(quotes)
+    }
+
+    /// Get a quote only if it has not yet expired.
+    /// Unlike `get_quote`, expired quotes are rejected with `StaleQuote`.
+    pub fn get_active_quote(env: Env, anchor: Address, quote_id: u64) -> Result<QuoteData, Error> {
+        let quote = Storage::get_quote(&env, &anchor, quote_id).ok_or(Error::InvalidQuote)?;
+
+        if quote.valid_until <= env.ledger().timestamp() {
+            return Err(Error::StaleQuote);
+        }
+
+        Ok(quote)
+    }
+
     /// Normalize deposit response to standard format
     pub fn normalize_deposit_response(
         env: Env,
@@ -832,6 +968,60 @@ impl AnchorKitContract {
         })
     }
 
+    /// Compare rates for a target output amount and return the option needing the least input.
+    /// `request.amount` is the amount the recipient should receive. Quotes whose
+    /// bounds cannot accommodate the required input are skipped.
+    pub fn compare_rates_for_output(
+        env: Env,
+        request: QuoteRequest,
+        anchors: Vec<Address>,
+    ) -> Result<RateComparison, Error> {
+        let current_timestamp = env.ledger().timestamp();
+        let mut valid_quotes: Vec<QuoteData> = Vec::new(&env);
+        let mut best: Option<(QuoteData, u64)> = None;
+
+        for anchor in anchors.iter() {
+            let quote = match Self::get_latest_quote_for_anchor(&env, &anchor, &request) {
+                Some(q) => q,
+                None => continue,
+            };
+
+            if quote.valid_until <= current_timestamp
+                || quote.base_asset != request.base_asset
+                || quote.quote_asset != request.quote_asset
+            {
+                continue;
+            }
+
+            let required_input = match Self::calculate_required_input(&quote, request.amount) {
+                Some(input) => input,
+                None => continue,
+            };
+
+            if required_input < quote.minimum_amount || required_input > quote.maximum_amount {
+                continue;
+            }
+
+            let is_better = match &best {
+                Some((_, best_input)) => required_input < *best_input,
+                None => true,
+            };
+            if is_better {
+                best = Some((quote.clone(), required_input));
+            }
+
+            valid_quotes.push_back(quote);
+        }
+
+        let (best_quote, _) = best.ok_or(Error::NoQuotesAvailable)?;
+
+        Ok(RateComparison {
+            best_quote,
+            all_quotes: valid_quotes,
+            comparison_timestamp: current_timestamp,
+        })
+    }
+
     fn validate_services(services: &Vec<ServiceType>) -> Result<(), Error> {
         if services.is_empty() {
             return Err(Error::InvalidServiceType);
@@ -855,6 +1045,26 @@ impl AnchorKitContract {
         Ok(())
     }
 
+    fn normalize_services(services: &Vec<ServiceType>) -> Vec<ServiceType> {
+        let mut sorted = services.clone();
+        for i in 0..sorted.len() {
+            for j in (i + 1)..sorted.len() {
+                let service_i = sorted.get(i).unwrap();
+                let service_j = sorted.get(j).unwrap();
+                if Self::service_rank(&service_j) < Self::service_rank(&service_i) {
+                    sorted.set(i, service_j);
+                    sorted.set(j, service_i);
+                }
+            }
+        }
+        sorted
+    }
+
+    /// Sort key for a service type, its discriminant.
+    fn service_rank(service: &ServiceType) -> u32 {
+        service.clone() as u32
+    }
+
     fn validate_transaction_operation(operation_type: &ServiceType) -> Result<(), Error> {
         match operation_type {
             ServiceType::Deposits | ServiceType::Withdrawals => Ok(()),
@@ -906,6 +1116,16 @@ impl AnchorKitContract {
         (base_rate * effective_amount) / amount
     }
 
+    /// Input needed for `target_output`, the inverse of `calculate_effective_rate`.
+    /// Rates are 4-decimal fixed point (10000 = 1.0) expressed as input per unit of
+    /// output; rounding is upwards so the target is always reached.
+    fn calculate_required_input(quote: &QuoteData, target_output: u64) -> Option<u64> {
+        let base_input = (target_output as u128 * quote.rate as u128).div_ceil(RATE_PRECISION);
+        let fee_amount = (base_input * quote.fee_percentage as u128).div_ceil(10000);
+
+        u64::try_from(base_input + fee_amount).ok()
+    }
+
     fn get_latest_quote_for_anchor(
         env: &Env,
         anchor: &Address,
@@ -922,13 +1142,128 @@ impl AnchorKitContract {
             return Err(Error::InvalidEndpointFormat);
         }
 
-        if len < 8 {
+        // Must be at least "https://" plus a host
+        if len <= 8 {
+            return Err(Error::InvalidEndpointFormat);
+        }
+
+        let mut buf = [0u8; 256];
+        let bytes = &mut buf[..len as usize];
+        url.copy_into_slice(bytes);
+
+        if !bytes.starts_with(b"https://") {
+            return Err(Error::InvalidEndpointFormat);
+        }
+
+        if bytes.iter().any(|b| b.is_ascii_whitespace()) {
+            return Err(Error::InvalidEndpointFormat);
+        }
+
+        Ok(())
+    }
+
+    /// Validate a bare hostname: no scheme, path or trailing dot/slash.
+    fn validate_home_domain_format(domain: &String) -> Result<(), Error> {
+        let len = domain.len();
+
+        if len == 0 || len > 253 {
+            return Err(Error::InvalidEndpointFormat);
+        }
+
+        let mut buf = [0u8; 253];
+        let bytes = &mut buf[..len as usize];
+        domain.copy_into_slice(bytes);
+
+        let mut label_len = 0u32;
+        for (i, b) in bytes.iter().enumerate() {
+            match b {
+                b'.' => {
+                    // Reject empty labels (leading dot, trailing dot, "..")
+                    // and labels ending in a hyphen
+                    if label_len == 0 || i + 1 == bytes.len() || bytes[i - 1] == b'-' {
+                        return Err(Error::InvalidEndpointFormat);
+                    }
+                    label_len = 0;
+                }
+                b'-' => {
+                    if label_len == 0 {
+                        return Err(Error::InvalidEndpointFormat);
+                    }
+                    label_len += 1;
+                }
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => label_len += 1,
+                // Anything else, including ':' and '/', rules out schemes and paths
+                _ => return Err(Error::InvalidEndpointFormat),
+            }
+
+            if label_len > 63 {
+                return Err(Error::InvalidEndpointFormat);
+            }
+        }
+
+        if bytes.ends_with(b"-") {
             return Err(Error::InvalidEndpointFormat);
         }
 
         Ok(())
     }
 
+    /// Check that the anchor's cached web auth endpoint host is exactly `home_domain`.
+    /// Parent-domain matches are not accepted: without a public suffix list a claim
+    /// such as "co.uk" cannot be told apart from a registrable domain.
+    /// Fails closed: without a cached stellar.toml or web auth endpoint the binding
+    /// cannot be shown, so authentication is refused.
+    fn verify_home_domain_binding(
+        env: &Env,
+        anchor: &Address,
+        home_domain: &String,
+    ) -> Result<(), Error> {
+        let toml = match anchor_info_discovery::AnchorInfoDiscovery::get_cached(env, anchor) {
+            Ok(toml) => toml,
+            Err(_) => return Err(Error::ComplianceNotMet),
+        };
+
+        let endpoint = toml.web_auth_endpoint;
+        if endpoint.is_empty() {
+            return Err(Error::ComplianceNotMet);
+        }
+
+        let endpoint_len = endpoint.len();
+        let domain_len = home_domain.len();
+        if endpoint_len > 256 || domain_len > 253 {
+            return Err(Error::ComplianceNotMet);
+        }
+
+        let mut endpoint_buf = [0u8; 256];
+        let endpoint_bytes = &mut endpoint_buf[..endpoint_len as usize];
+        endpoint.copy_into_slice(endpoint_bytes);
+
+        let mut domain_buf = [0u8; 253];
+        let domain_bytes = &mut domain_buf[..domain_len as usize];
+        home_domain.copy_into_slice(domain_bytes);
+
+        // Strip the scheme, then cut the host at the first port or path separator
+        let without_scheme = if endpoint_bytes.starts_with(b"https://") {
+            &endpoint_bytes[8..]
+        } else if endpoint_bytes.starts_with(b"http://") {
+            &endpoint_bytes[7..]
+        } else {
+            &endpoint_bytes[..]
+        };
+        let host_end = without_scheme
+            .iter()
+            .position(|b| *b == b'/' || *b == b':')
+            .unwrap_or(without_scheme.len());
+        let host = &without_scheme[..host_end];
+
+        let domain: &[u8] = domain_bytes;
+        if !host.eq_ignore_ascii_case(domain) {
+            return Err(Error::ComplianceNotMet);
+        }
+
+        Ok(())
+    }
+
     fn verify_signature(
         _env: &Env,
         _issuer: &Address,
@@ -1157,6 +1492,10 @@ impl AnchorKitContract {
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
+        if capabilities.len() > MAX_CAPABILITIES_LENGTH {
+            return Err(Error::WebhookPayloadTooLarge);
+        }
+
         MetadataCache::set_capabilities(&env, &anchor, toml_url, capabilities, ttl_seconds);
         Ok(())
     }
@@ -1269,6 +1608,32 @@ impl AnchorKitContract {
         anchor_info_discovery::AnchorInfoDiscovery::get_withdrawal_fees(&env, &anchor, &asset_code)
     }
 
+    /// Estimate the SEP-24 fee for an amount from the cached stellar.toml
+    /// Computes `fixed + amount * percent / 10000` for deposits or withdrawals
+    pub fn estimate_sep24_fee(
+        env: Env,
+        anchor: Address,
+        asset_code: String,
+        amount: u64,
+        is_deposit: bool,
+    ) -> Result<u64, Error> {
+        let (fee_fixed, fee_percent) = if is_deposit {
+            anchor_info_discovery::AnchorInfoDiscovery::get_deposit_fees(&env, &anchor, &asset_code)?
+        } else {
+            anchor_info_discovery::AnchorInfoDiscovery::get_withdrawal_fees(
+                &env,
+                &anchor,
+                &asset_code,
+            )?
+        };
+
+        // Widen to u128 so the percentage product cannot overflow
+        let percent_fee = (amount as u128 * fee_percent as u128) / 10000;
+        let total = fee_fixed as u128 + percent_fee;
+
+        u64::try_from(total).map_err(|_| Error::ProtocolInvalidPayload)
+    }
+
     /// Check if asset supports deposits
     pub fn anchor_supports_deposits(
         env: Env,
@@ -1329,6 +1694,24 @@ impl AnchorKitContract {
         Storage::get_health_status(&env, &anchor)
     }
 
+    /// Get health status for several anchors at once.
+    /// Results are aligned to the input order, with `None` for anchors without health data.
+    pub fn get_health_statuses(
+        env: Env,
+        anchors: Vec<Address>,
+    ) -> Result<Vec<Option<HealthStatus>>, Error> {
+        if anchors.len() > MAX_BATCH_READ_SIZE {
+            return Err(Error::WebhookPayloadTooLarge);
+        }
+
+        let mut statuses: Vec<Option<HealthStatus>> = Vec::new(&env);
+        for anchor in anchors.iter() {
+            statuses.push_back(Storage::get_health_status(&env, &anchor));
+        }
+
+        Ok(statuses)
+    }
+
     /// Configure rate limiting for an anchor. Only callable by admin.
     pub fn configure_rate_limit(
         env: Env,
@@ -1362,6 +1745,9 @@ impl AnchorKitContract {
     ) -> Result<RoutingResult, Error> {
         Storage::get_admin(&env)?;
 
+        // Only deposits and withdrawals can be routed as transactions
+        Self::validate_transaction_operation(&routing_request.request.operation_type)?;
+
         let current_timestamp = env.ledger().timestamp();
         let anchors = Storage::get_anchor_list(&env);
 
@@ -1524,8 +1910,7 @@ impl AnchorKitContract {
             }
             RoutingStrategy::LowestFee => {
                 // Lower fee is better
-                let max_fee = 10000u32; // 100%
-                let fee_score = max_fee.saturating_sub(quote.fee_percentage);
+                let fee_score = MAX_FEE_PERCENTAGE.saturating_sub(quote.fee_percentage);
                 fee_score as u64 * 100_000
             }
             RoutingStrategy::FastestSettlement => {
@@ -1689,10 +2074,27 @@ impl AnchorKitContract {
             )));
         }
 
+        // Step 4: Report endpoint health (informational, never pending)
+        if Storage::get_health_status(&env, &attestor).is_some() {
+            steps.push_back(ValidationStep::complete(String::from_str(
+                &env,
+                "Endpoint health reported",
+            )));
+        } else {
+            steps.push_back(ValidationStep {
+                step_name: String::from_str(&env, "No endpoint health reported"),
+                is_complete: false,
+                is_loading: false,
+            });
+        }
+
         // Determine overall validation state
         let all_complete = has_policy && has_endpoint;
         if all_complete {
-            Ok(AuthValidationSkeleton::validated(&env, attestor))
+            // Keep the steps so the health step is still visible once validated
+            let mut skeleton = AuthValidationSkeleton::validated(&env, attestor);
+            skeleton.validation_steps = steps;
+            Ok(skeleton)
         } else {
             Ok(AuthValidationSkeleton::validating_with_steps(
                 attestor, steps,
@@ -1702,6 +2104,9 @@ impl AnchorKitContract {
     // ============ Connection Pooling ============
 
     /// Configure connection pool. Only callable by admin.
+    /// `connection_timeout_seconds` bounds establishing a connection, while
+    /// `idle_timeout_seconds` bounds how long an idle pooled connection is kept
+    /// for reuse. The two are independent, but reuse needs a non-zero idle timeout.
     pub fn configure_connection_pool(
         env: Env,
         max_connections: u32,
@@ -1712,6 +2117,14 @@ impl AnchorKitContract {
         let admin = Storage::get_admin(&env)?;
         admin.require_auth();
 
+        if max_connections == 0 || connection_timeout_seconds == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        if reuse_connections && idle_timeout_seconds == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
         let config = ConnectionPoolConfig {
             max_connections,
             idle_timeout_seconds,
@@ -1778,20 +2191,15 @@ impl AnchorKitContract {
         );
         let completed_at = env.ledger().timestamp();
 
-        let status = if result.is_ok() {
-            String::from_str(&env, "success")
-        } else {
-            String::from_str(&env, "failed")
-        };
-        let span = TracingSpan {
-            request_id: request_id.clone(),
-            operation: String::from_str(&env, "submit_attestation"),
-            actor: issuer.clone(),
+        Self::store_call_span(
+            &env,
+            &request_id,
+            "submit_attestation",
+            &issuer,
             started_at,
             completed_at,
-            status,
-        };
-        RequestTracker::store_span(&env, &span);
+            result.is_ok(),
+        );
 
         result
     }
@@ -1825,20 +2233,15 @@ impl AnchorKitContract {
         );
         let completed_at = env.ledger().timestamp();
 
-        let status = if result.is_ok() {
-            String::from_str(&env, "success")
-        } else {
-            String::from_str(&env, "failed")
-        };
-        let span = TracingSpan {
-            request_id: request_id.clone(),
-            operation: String::from_str(&env, "submit_quote"),
-            actor: anchor.clone(),
+        Self::store_call_span(
+            &env,
+            &request_id,
+            "submit_quote",
+            &anchor,
             started_at,
             completed_at,
-            status,
-        };
-        RequestTracker::store_span(&env, &span);
+            result.is_ok(),
+        );
 
         result
     }
@@ -1848,6 +2251,23 @@ impl AnchorKitContract {
         RequestTracker::get_span(&env, &request_id)
     }
 
+    /// Reject zero timestamps and timestamps too far ahead of ledger time.
+    fn validate_attestation_timestamp(env: &Env, timestamp: u64) -> Result<(), Error> {
+        if timestamp == 0 {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        let latest_allowed = env
+            .ledger()
+            .timestamp()
+            .saturating_add(MAX_ATTESTATION_FUTURE_SKEW);
+        if timestamp > latest_allowed {
+            return Err(Error::InvalidTimestamp);
+        }
+
+        Ok(())
+    }
+
     fn submit_attestation_internal(
         env: &Env,
         issuer: &Address,
@@ -1856,9 +2276,7 @@ impl AnchorKitContract {
         payload_hash: &BytesN<32>,
         signature: &Bytes,
     ) -> Result<u64, Error> {
-        if timestamp == 0 {
-            return Err(Error::InvalidTimestamp);
-        }
+        Self::validate_attestation_timestamp(env, timestamp)?;
 
         if !Storage::is_attestor(env, issuer) {
             return Err(Error::UnauthorizedAttestor);
@@ -1905,6 +2323,12 @@ impl AnchorKitContract {
         RequestHistory::get_call(&env, call_id)
     }
 
+    /// Get the tracing span linked to an API call record through its request ID
+    pub fn get_span_for_call(env: Env, call_id: u64) -> Option<TracingSpan> {
+        let record = RequestHistory::get_call(&env, call_id)?;
+        RequestTracker::get_span(&env, &record.request_id)
+    }
+
     /// Submit attestation with automatic request history tracking
     pub fn submit_attestation_tracked(
         env: Env,
@@ -1952,6 +2376,15 @@ impl AnchorKitContract {
         }
 
         RequestHistory::record_call(&env, &record);
+        Self::store_call_span(
+            &env,
+            &request_id,
+            "submit_attestation",
+            &issuer,
+            started_at,
+            completed_at,
+            result.is_ok(),
+        );
 
         // Store detailed information
         if let Ok(attestation_id) = &result {
@@ -2020,6 +2453,15 @@ impl AnchorKitContract {
         }
 
         RequestHistory::record_call(&env, &record);
+        Self::store_call_span(
+            &env,
+            &request_id,
+            "submit_quote",
+            &anchor,
+            started_at,
+            completed_at,
+            result.is_ok(),
+        );
 
         // Store detailed information
         if let Ok(quote_id) = &result {
@@ -2069,6 +2511,15 @@ impl AnchorKitContract {
         }
 
         RequestHistory::record_call(&env, &record);
+        Self::store_call_span(
+            &env,
+            &request_id,
+            "register_attestor",
+            &admin,
+            started_at,
+            completed_at,
+            result.is_ok(),
+        );
 
         // Store detailed information
         let details = ApiCallDetails {
@@ -2082,6 +2533,31 @@ impl AnchorKitContract {
         result
     }
 
+    fn store_call_span(
+        env: &Env,
+        request_id: &RequestId,
+        operation: &str,
+        actor: &Address,
+        started_at: u64,
+        completed_at: u64,
+        success: bool,
+    ) {
+        let status = if success {
+            String::from_str(env, "success")
+        } else {
+            String::from_str(env, "failed")
+        };
+        let span = TracingSpan {
+            request_id: request_id.clone(),
+            operation: String::from_str(env, operation),
+            actor: actor.clone(),
+            started_at,
+            completed_at,
+            status,
+        };
+        RequestTracker::store_span(env, &span);
+    }
+
     // ============ Interactive Support ============
 
     /// Generate interactive URL with embedded token
@@ -2185,6 +2661,7 @@ impl AnchorKitContract {
         if !Storage::is_attestor(&env, &anchor) {
             return Err(Error::AttestorNotRegistered);
         }
+        Self::validate_home_domain_format(&home_domain)?;
         Ok(sep10_auth::validate_home_domain(&env, anchor, home_domain))
     }
 
@@ -2214,6 +2691,8 @@ impl AnchorKitContract {
         if !Storage::is_attestor(&env, &anchor) {
             return Err(Error::AttestorNotRegistered);
         }
+        Self::validate_home_domain_format(&home_domain)?;
+        Self::verify_home_domain_binding(&env, &anchor, &home_domain)?;
         sep10_auth::authenticate(
             &env,
             anchor,
@@ -0,0 +1,281 @@
+#[cfg(test)]
+mod quote_tests {
+    use crate::test_support::{register_anchor, setup, setup_with_quote_anchor};
+    use crate::{AnchorKitContractClient, Error, QuoteRequest, ServiceType};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        vec, Address, Env, String,
+    };
+
+    fn try_submit(
+        env: &Env,
+        client: &AnchorKitContractClient,
+        anchor: &Address,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+    ) -> Result<u64, Error> {
+        match client.try_submit_quote(
+            anchor,
+            &String::from_str(env, "USD"),
+            &String::from_str(env, "USDC"),
+            &rate,
+            &fee_percentage,
+            &minimum_amount,
+            &maximum_amount,
+            &valid_until,
+        ) {
+            Ok(Ok(quote_id)) => Ok(quote_id),
+            Err(Ok(error)) => Err(error),
+            _ => panic!("unexpected invocation failure"),
+        }
+    }
+
+    fn submit(
+        env: &Env,
+        client: &AnchorKitContractClient,
+        anchor: &Address,
+        valid_until: u64,
+    ) -> u64 {
+        try_submit(env, client, anchor, 10000, 50, 100, 100000, valid_until).unwrap()
+    }
+
+    fn try_submit_bounds(
+        env: &Env,
+        client: &AnchorKitContractClient,
+        anchor: &Address,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+    ) -> Result<u64, Error> {
+        try_submit(
+            env,
+            client,
+            anchor,
+            10000,
+            fee_percentage,
+            minimum_amount,
+            maximum_amount,
+            1_003_600,
+        )
+    }
+
+    // ============ Accessors ============
+
+    #[test]
+    fn test_get_active_quote_returns_fresh_quote() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+        let quote_id = submit(&env, &client, &anchor, 1_003_600);
+
+        let quote = client.get_active_quote(&anchor, &quote_id);
+        assert_eq!(quote.quote_id, quote_id);
+        assert_eq!(quote.rate, 10000);
+    }
+
+    #[test]
+    fn test_get_active_quote_rejects_expired_quote() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+        let quote_id = submit(&env, &client, &anchor, 1_003_600);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_003_600);
+
+        let result = client.try_get_active_quote(&anchor, &quote_id);
+        assert_eq!(result, Err(Ok(Error::StaleQuote)));
+
+        // The raw accessor still returns the expired quote
+        let quote = client.get_quote(&anchor, &quote_id);
+        assert_eq!(quote.quote_id, quote_id);
+    }
+
+    #[test]
+    fn test_get_active_quote_unknown_quote() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        let result = client.try_get_active_quote(&anchor, &42);
+        assert_eq!(result, Err(Ok(Error::InvalidQuote)));
+    }
+
+    #[test]
+    fn test_get_quotes_mixed_refs() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+        let first = submit(&env, &client, &anchor, 1_003_600);
+        let second = submit(&env, &client, &anchor, 1_007_200);
+        let stranger = Address::generate(&env);
+
+        let quotes = client.get_quotes(&vec![
+            &env,
+            (anchor.clone(), first),
+            (anchor.clone(), 999),
+            (stranger, first),
+            (anchor.clone(), second),
+        ]);
+
+        assert_eq!(quotes.len(), 4);
+        assert_eq!(quotes.get(0).unwrap().unwrap().quote_id, first);
+        assert!(quotes.get(1).unwrap().is_none());
+        assert!(quotes.get(2).unwrap().is_none());
+        assert_eq!(quotes.get(3).unwrap().unwrap().quote_id, second);
+    }
+
+    #[test]
+    fn test_get_quotes_rejects_oversized_batch() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        let mut refs = vec![&env];
+        for quote_id in 0..51u64 {
+            refs.push_back((anchor.clone(), quote_id));
+        }
+
+        let result = client.try_get_quotes(&refs);
+        assert_eq!(result, Err(Ok(Error::WebhookPayloadTooLarge)));
+    }
+
+    // ============ Submission validation ============
+
+    #[test]
+    fn test_reject_inverted_amount_bounds() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        let result = try_submit_bounds(&env, &client, &anchor, 50, 10000, 100);
+        assert_eq!(result, Err(Error::InvalidQuote));
+    }
+
+    #[test]
+    fn test_accept_valid_amount_bounds() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        let quote_id = try_submit_bounds(&env, &client, &anchor, 50, 100, 10000).unwrap();
+        let quote = client.get_quote(&anchor, &quote_id);
+        assert_eq!(quote.minimum_amount, 100);
+        assert_eq!(quote.maximum_amount, 10000);
+    }
+
+    #[test]
+    fn test_accept_equal_amount_bounds() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        assert!(try_submit_bounds(&env, &client, &anchor, 50, 500, 500).is_ok());
+    }
+
+    #[test]
+    fn test_accept_full_fee_percentage() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        // 10000 basis points = 100%
+        assert!(try_submit_bounds(&env, &client, &anchor, 10000, 100, 10000).is_ok());
+    }
+
+    #[test]
+    fn test_reject_fee_percentage_above_100_percent() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        let result = try_submit_bounds(&env, &client, &anchor, 10001, 100, 10000);
+        assert_eq!(result, Err(Error::InvalidQuote));
+    }
+
+    #[test]
+    fn test_accept_zero_fee_percentage() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_quote_anchor(&env);
+
+        assert!(try_submit_bounds(&env, &client, &anchor, 0, 100, 10000).is_ok());
+    }
+
+    // ============ Output-targeted comparison ============
+
+    fn anchor_with_quote(
+        env: &Env,
+        client: &AnchorKitContractClient,
+        rate: u64,
+        fee_percentage: u32,
+        maximum_amount: u64,
+    ) -> Address {
+        let anchor = register_anchor(env, client);
+        client.configure_services(
+            &anchor,
+            &vec![env, ServiceType::Deposits, ServiceType::Quotes],
+        );
+        try_submit(
+            env,
+            client,
+            &anchor,
+            rate,
+            fee_percentage,
+            100,
+            maximum_amount,
+            1_003_600,
+        )
+        .unwrap();
+        anchor
+    }
+
+    fn output_request(env: &Env, target_output: u64) -> QuoteRequest {
+        QuoteRequest {
+            base_asset: String::from_str(env, "USD"),
+            quote_asset: String::from_str(env, "USDC"),
+            amount: target_output,
+            operation_type: ServiceType::Deposits,
+        }
+    }
+
+    #[test]
+    fn test_least_required_input_wins() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        // 10000 base + 0.5% fee = 10050 input
+        let cheap = anchor_with_quote(&env, &client, 10000, 50, 100000);
+        // 9900 base + 2% fee = 10098 input
+        let pricey = anchor_with_quote(&env, &client, 9900, 200, 100000);
+
+        let comparison = client.compare_rates_for_output(
+            &output_request(&env, 10000),
+            &vec![&env, pricey, cheap.clone()],
+        );
+
+        assert_eq!(comparison.best_quote.anchor, cheap);
+        assert_eq!(comparison.all_quotes.len(), 2);
+    }
+
+    #[test]
+    fn test_quote_unable_to_reach_target_is_skipped() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        // Cheapest rate, but 9000 input exceeds its 5000 maximum
+        let capped = anchor_with_quote(&env, &client, 9000, 0, 5000);
+        let fallback = anchor_with_quote(&env, &client, 10000, 50, 100000);
+
+        let comparison = client.compare_rates_for_output(
+            &output_request(&env, 10000),
+            &vec![&env, capped, fallback.clone()],
+        );
+
+        assert_eq!(comparison.best_quote.anchor, fallback);
+        assert_eq!(comparison.all_quotes.len(), 1);
+    }
+
+    #[test]
+    fn test_no_quote_reaches_target() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let capped = anchor_with_quote(&env, &client, 10000, 0, 5000);
+
+        let result =
+            client.try_compare_rates_for_output(&output_request(&env, 10000), &vec![&env, capped]);
+        assert_eq!(result, Err(Ok(Error::NoQuotesAvailable)));
+    }
+}
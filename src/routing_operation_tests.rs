@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod routing_operation_tests {
+    use crate::test_support::setup_with_quote_anchor;
+    use crate::{
+        AnchorKitContractClient, Error, QuoteRequest, RoutingRequest, RoutingStrategy, ServiceType,
+    };
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup_routable_anchor(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        let (client, anchor) = setup_with_quote_anchor(env);
+        client.set_anchor_metadata(&anchor, &9000, &3600, &8000, &9900, &1_000_000);
+        client.submit_quote(
+            &anchor,
+            &String::from_str(env, "USD"),
+            &String::from_str(env, "USDC"),
+            &10000,
+            &50,
+            &100,
+            &100000,
+            &1_003_600,
+        );
+
+        (client, anchor)
+    }
+
+    fn routing_request(env: &Env, operation_type: ServiceType) -> RoutingRequest {
+        RoutingRequest {
+            request: QuoteRequest {
+                base_asset: String::from_str(env, "USD"),
+                quote_asset: String::from_str(env, "USDC"),
+                amount: 1000,
+                operation_type,
+            },
+            strategy: RoutingStrategy::BestRate,
+            max_anchors: 3,
+            require_kyc: false,
+            min_reputation: 0,
+        }
+    }
+
+    #[test]
+    fn test_find_best_anchor_accepts_deposits() {
+        let env = Env::default();
+        let (client, anchor) = setup_routable_anchor(&env);
+
+        let best = client.find_best_anchor(
+            &String::from_str(&env, "USD"),
+            &String::from_str(&env, "USDC"),
+            &1000,
+            &ServiceType::Deposits,
+            &RoutingStrategy::BestRate,
+        );
+        assert_eq!(best, anchor);
+    }
+
+    #[test]
+    fn test_find_best_anchor_rejects_quotes_operation() {
+        let env = Env::default();
+        let (client, _anchor) = setup_routable_anchor(&env);
+
+        let result = client.try_find_best_anchor(
+            &String::from_str(&env, "USD"),
+            &String::from_str(&env, "USDC"),
+            &1000,
+            &ServiceType::Quotes,
+            &RoutingStrategy::BestRate,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+    }
+
+    #[test]
+    fn test_route_transaction_rejects_kyc_operation() {
+        let env = Env::default();
+        let (client, _anchor) = setup_routable_anchor(&env);
+
+        let result = client.try_route_transaction(&routing_request(&env, ServiceType::KYC));
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+    }
+
+    #[test]
+    fn test_route_transaction_accepts_deposits() {
+        let env = Env::default();
+        let (client, anchor) = setup_routable_anchor(&env);
+
+        let result = client.route_transaction(&routing_request(&env, ServiceType::Deposits));
+        assert_eq!(result.selected_anchor, anchor);
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod sep10_domain_tests {
+    use crate::test_support::{cache_example_toml, setup_with_anchor};
+    use crate::{AnchorKitContract, AnchorKitContractClient, Error};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+    fn validate(env: &Env, domain: &str) -> Result<(), Error> {
+        AnchorKitContract::validate_home_domain_format(&String::from_str(env, domain))
+    }
+
+    #[test]
+    fn test_valid_domains() {
+        let env = Env::default();
+        assert_eq!(validate(&env, "example.com"), Ok(()));
+        assert_eq!(validate(&env, "testanchor.stellar.org"), Ok(()));
+        assert_eq!(validate(&env, "my-anchor.example.com"), Ok(()));
+    }
+
+    #[test]
+    fn test_reject_scheme_prefixed_domain() {
+        let env = Env::default();
+        assert_eq!(
+            validate(&env, "https://example.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_trailing_slash_and_path() {
+        let env = Env::default();
+        assert_eq!(
+            validate(&env, "example.com/"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "example.com/auth"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_malformed_labels() {
+        let env = Env::default();
+        assert_eq!(validate(&env, ""), Err(Error::InvalidEndpointFormat));
+        assert_eq!(
+            validate(&env, ".example.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "example..com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "example.com."),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "-anchor.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "anchor-.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+        assert_eq!(
+            validate(&env, "exa mple.com"),
+            Err(Error::InvalidEndpointFormat)
+        );
+    }
+
+    #[test]
+    fn test_reject_oversized_domain() {
+        let env = Env::default();
+        let mut domain = alloc::string::String::new();
+        for _ in 0..50 {
+            domain.push_str("abcd.");
+        }
+        domain.push_str("example.com");
+        assert!(domain.len() > 253);
+
+        assert_eq!(validate(&env, &domain), Err(Error::InvalidEndpointFormat));
+    }
+
+    #[test]
+    fn test_reject_oversized_label() {
+        let env = Env::default();
+        let mut domain = alloc::string::String::new();
+        for _ in 0..64 {
+            domain.push('a');
+        }
+        domain.push_str(".com");
+
+        assert_eq!(validate(&env, &domain), Err(Error::InvalidEndpointFormat));
+    }
+
+    #[test]
+    fn test_sep10_validate_domain_returns_typed_error() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let result = client
+            .try_sep10_validate_domain(&anchor, &String::from_str(&env, "https://example.com"));
+        assert_eq!(result, Err(Ok(Error::InvalidEndpointFormat)));
+
+        let result =
+            client.try_sep10_validate_domain(&anchor, &String::from_str(&env, "example.com"));
+        assert!(result.is_ok());
+    }
+
+    fn setup_with_toml(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+        let (client, anchor) = setup_with_anchor(env);
+        cache_example_toml(env, &client, &anchor);
+        (client, anchor)
+    }
+
+    #[test]
+    fn test_home_domain_matches_cached_toml() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_toml(&env);
+
+        env.as_contract(&client.address, || {
+            let result = AnchorKitContract::verify_home_domain_binding(
+                &env,
+                &anchor,
+                &String::from_str(&env, "auth.example.com"),
+            );
+            assert_eq!(result, Ok(()));
+
+            // Host comparison is case-insensitive
+            let result = AnchorKitContract::verify_home_domain_binding(
+                &env,
+                &anchor,
+                &String::from_str(&env, "Auth.Example.com"),
+            );
+            assert_eq!(result, Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_home_domain_mismatch_rejected() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_toml(&env);
+
+        env.as_contract(&client.address, || {
+            let result = AnchorKitContract::verify_home_domain_binding(
+                &env,
+                &anchor,
+                &String::from_str(&env, "evil.com"),
+            );
+            assert_eq!(result, Err(Error::ComplianceNotMet));
+
+            let result = AnchorKitContract::verify_home_domain_binding(
+                &env,
+                &anchor,
+                &String::from_str(&env, "ample.com"),
+            );
+            assert_eq!(result, Err(Error::ComplianceNotMet));
+        });
+    }
+
+    #[test]
+    fn test_sep10_authenticate_rejects_mismatched_domain() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_toml(&env);
+
+        let result = client.try_sep10_authenticate(
+            &anchor,
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[0u8; 64]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "other.org"),
+        );
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+    }
+
+    #[test]
+    fn test_parent_domains_rejected() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_toml(&env);
+
+        // None of these may bind auth.example.com: a parent match cannot tell a
+        // public suffix such as "co.uk" apart from a registrable domain
+        env.as_contract(&client.address, || {
+            for parent in ["example.com", "com"] {
+                let result = AnchorKitContract::verify_home_domain_binding(
+                    &env,
+                    &anchor,
+                    &String::from_str(&env, parent),
+                );
+                assert_eq!(result, Err(Error::ComplianceNotMet));
+            }
+        });
+    }
+
+    #[test]
+    fn test_home_domain_binding_fails_closed_without_toml() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        env.as_contract(&client.address, || {
+            let result = AnchorKitContract::verify_home_domain_binding(
+                &env,
+                &anchor,
+                &String::from_str(&env, "example.com"),
+            );
+            assert_eq!(result, Err(Error::ComplianceNotMet));
+        });
+
+        let result = client.try_sep10_authenticate(
+            &anchor,
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[0u8; 64]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "example.com"),
+        );
+        assert_eq!(result, Err(Ok(Error::ComplianceNotMet)));
+    }
+}
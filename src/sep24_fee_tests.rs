@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod sep24_fee_tests {
+    use crate::test_support::{cache_example_toml, setup_with_anchor};
+    use crate::Error;
+    use soroban_sdk::{Env, String};
+
+    #[test]
+    fn test_estimate_deposit_fee() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+        cache_example_toml(&env, &client, &anchor);
+
+        // USDC deposit: 100 fixed + 10 bps
+        let fee =
+            client.estimate_sep24_fee(&anchor, &String::from_str(&env, "USDC"), &10000, &true);
+        assert_eq!(fee, 110);
+    }
+
+    #[test]
+    fn test_estimate_withdrawal_fee() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+        cache_example_toml(&env, &client, &anchor);
+
+        // USDC withdrawal: 50 fixed + 5 bps
+        let fee =
+            client.estimate_sep24_fee(&anchor, &String::from_str(&env, "USDC"), &10000, &false);
+        assert_eq!(fee, 55);
+    }
+
+    #[test]
+    fn test_estimate_fee_zero_fee_asset() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+        cache_example_toml(&env, &client, &anchor);
+
+        let fee = client.estimate_sep24_fee(&anchor, &String::from_str(&env, "XLM"), &10000, &true);
+        assert_eq!(fee, 0);
+    }
+
+    #[test]
+    fn test_estimate_fee_missing_asset() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+        cache_example_toml(&env, &client, &anchor);
+
+        let result =
+            client.try_estimate_sep24_fee(&anchor, &String::from_str(&env, "EUR"), &10000, &true);
+        assert_eq!(result, Err(Ok(Error::UnsupportedAsset)));
+    }
+
+    #[test]
+    fn test_estimate_fee_missing_toml() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let result =
+            client.try_estimate_sep24_fee(&anchor, &String::from_str(&env, "USDC"), &10000, &true);
+        assert_eq!(result, Err(Ok(Error::CacheNotFound)));
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod service_tests {
+    use crate::test_support::{cache_example_toml, register_anchor, setup_with_anchor};
+    use crate::{Error, ServiceType};
+    use soroban_sdk::{vec, Env};
+
+    // ============ Normalization ============
+
+    #[test]
+    fn test_services_stored_in_canonical_order() {
+        let env = Env::default();
+        let (client, anchor_a) = setup_with_anchor(&env);
+        let anchor_b = register_anchor(&env, &client);
+
+        client.configure_services(
+            &anchor_a,
+            &vec![&env, ServiceType::Deposits, ServiceType::KYC],
+        );
+        client.configure_services(
+            &anchor_b,
+            &vec![&env, ServiceType::KYC, ServiceType::Deposits],
+        );
+
+        let services_a = client.get_supported_services(&anchor_a);
+        let services_b = client.get_supported_services(&anchor_b);
+
+        assert_eq!(services_a, services_b);
+        assert_eq!(
+            services_a,
+            vec![&env, ServiceType::Deposits, ServiceType::KYC]
+        );
+    }
+
+    #[test]
+    fn test_full_service_set_sorted_by_discriminant() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        client.configure_services(
+            &anchor,
+            &vec![
+                &env,
+                ServiceType::KYC,
+                ServiceType::Quotes,
+                ServiceType::Withdrawals,
+                ServiceType::Deposits,
+            ],
+        );
+
+        let services = client.get_supported_services(&anchor);
+        for i in 1..services.len() {
+            assert!((services.get(i - 1).unwrap() as u32) < (services.get(i).unwrap() as u32));
+        }
+    }
+
+    #[test]
+    fn test_normalization_preserves_duplicate_rejection() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let result = client.try_configure_services(
+            &anchor,
+            &vec![
+                &env,
+                ServiceType::KYC,
+                ServiceType::Deposits,
+                ServiceType::KYC,
+            ],
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidServiceType)));
+    }
+
+    // ============ Effective services ============
+
+    #[test]
+    fn test_configured_services_take_precedence() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        client.configure_services(&anchor, &vec![&env, ServiceType::Quotes]);
+        cache_example_toml(&env, &client, &anchor);
+
+        let services = client.get_effective_services(&anchor);
+        assert_eq!(services, vec![&env, ServiceType::Quotes]);
+    }
+
+    #[test]
+    fn test_services_derived_from_cached_toml() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        cache_example_toml(&env, &client, &anchor);
+
+        let services = client.get_effective_services(&anchor);
+        assert!(services.contains(&ServiceType::Deposits));
+        assert!(services.contains(&ServiceType::Withdrawals));
+        assert!(services.contains(&ServiceType::KYC));
+        assert!(!services.contains(&ServiceType::Quotes));
+    }
+
+    #[test]
+    fn test_no_services_without_config_or_toml() {
+        let env = Env::default();
+        let (client, anchor) = setup_with_anchor(&env);
+
+        let services = client.get_effective_services(&anchor);
+        assert!(services.is_empty());
+
+        // The strict accessor still reports the missing configuration
+        assert!(client.try_get_supported_services(&anchor).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+//! Shared fixtures for contract-level tests.
+
+use crate::{AnchorKitContract, AnchorKitContractClient, ServiceType};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+/// Ledger time every fixture starts from.
+pub const START_TIME: u64 = 1_000_000;
+
+/// Register and initialize the contract with a fresh admin.
+pub fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = START_TIME);
+
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    client
+}
+
+/// Register a new attestor/anchor on an initialized contract.
+pub fn register_anchor(env: &Env, client: &AnchorKitContractClient) -> Address {
+    let anchor = Address::generate(env);
+    client.register_attestor(&anchor);
+    anchor
+}
+
+/// Initialized contract with one registered anchor.
+pub fn setup_with_anchor(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+    let client = setup(env);
+    let anchor = register_anchor(env, &client);
+    (client, anchor)
+}
+
+/// Initialized contract with one anchor offering deposits and quotes.
+pub fn setup_with_quote_anchor(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+    let (client, anchor) = setup_with_anchor(env);
+    client.configure_services(
+        &anchor,
+        &vec![env, ServiceType::Deposits, ServiceType::Quotes],
+    );
+    (client, anchor)
+}
+
+/// Cache the mock stellar.toml for `example.com` against an anchor.
+/// Its web_auth_endpoint is https://auth.example.com.
+pub fn cache_example_toml(env: &Env, client: &AnchorKitContractClient, anchor: &Address) {
+    client.fetch_anchor_info(anchor, &String::from_str(env, "example.com"), &None);
+}